@@ -1,11 +1,27 @@
 const PUBLICATION_API_ENDPOINT: &str = "https://dblp.org/search/publ/api";
 const AUTHOR_API_ENDPOINT: &str = "https://dblp.org/search/author/api";
 const VENUE_API_ENDPOINT: &str = "https://dblp.org/search/venue/api";
+/// Base URL for a publication's export record, e.g. `{REC_API_ENDPOINT}/{key}.bib`.
+const REC_API_ENDPOINT: &str = "https://dblp.org/rec";
+
+/// The offset and page size used by the non-paginated `search_*` functions.
+const DEFAULT_FIRST: usize = 0;
+const DEFAULT_COUNT: usize = 30;
+/// The largest page size the DBLP API accepts for the `h` parameter.
+const MAX_COUNT: usize = 1000;
+
+const DEFAULT_USER_AGENT: &str = concat!(env!("CARGO_PKG_NAME"), "/", env!("CARGO_PKG_VERSION"));
+/// How many times to retry a request that DBLP rate-limited, by default.
+const DEFAULT_MAX_RETRIES: u32 = 3;
+/// The backoff used when DBLP doesn't send a `Retry-After` header, doubled
+/// on each subsequent retry.
+const DEFAULT_BASE_DELAY: Duration = Duration::from_millis(500);
 
 extern crate anyhow;
 extern crate reqwest;
 extern crate serde;
 extern crate serde_json;
+extern crate tokio;
 
 use serde::de::DeserializeOwned;
 use serde::de::MapAccess;
@@ -13,48 +29,313 @@ use serde::de::SeqAccess;
 use serde::Deserialize;
 use serde_json::Value;
 use std::fmt;
+use std::marker::PhantomData;
+use std::sync::OnceLock;
+use std::time::Duration;
+
+/// A reusable DBLP API client.
+///
+/// Holds a single [`reqwest::Client`] so requests benefit from connection
+/// pooling, and carries the three search endpoints as configurable fields so
+/// tests (or mirrors) can point them at something other than dblp.org. The
+/// client negotiates gzip/brotli compression and sends a descriptive
+/// `User-Agent`, and retries requests DBLP rate-limits (HTTP 429) or can't
+/// currently serve (503), honoring the `Retry-After` header when present.
+pub struct DblpClient {
+    client: reqwest::Client,
+    publication_endpoint: String,
+    author_endpoint: String,
+    venue_endpoint: String,
+    rec_endpoint: String,
+    max_retries: u32,
+    base_delay: Duration,
+}
+
+impl Default for DblpClient {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DblpClient {
+    pub fn new() -> Self {
+        let client = reqwest::Client::builder()
+            .user_agent(DEFAULT_USER_AGENT)
+            .gzip(true)
+            .brotli(true)
+            .build()
+            .expect("failed to build the DBLP HTTP client");
+        DblpClient {
+            client,
+            publication_endpoint: PUBLICATION_API_ENDPOINT.to_owned(),
+            author_endpoint: AUTHOR_API_ENDPOINT.to_owned(),
+            venue_endpoint: VENUE_API_ENDPOINT.to_owned(),
+            rec_endpoint: REC_API_ENDPOINT.to_owned(),
+            max_retries: DEFAULT_MAX_RETRIES,
+            base_delay: DEFAULT_BASE_DELAY,
+        }
+    }
+
+    /// Overrides the publication search endpoint, e.g. to point at a mock
+    /// server in tests.
+    pub fn publication_endpoint(mut self, endpoint: impl Into<String>) -> Self {
+        self.publication_endpoint = endpoint.into();
+        self
+    }
+
+    /// Overrides the author search endpoint, e.g. to point at a mock server
+    /// in tests.
+    pub fn author_endpoint(mut self, endpoint: impl Into<String>) -> Self {
+        self.author_endpoint = endpoint.into();
+        self
+    }
+
+    /// Overrides the venue search endpoint, e.g. to point at a mock server
+    /// in tests.
+    pub fn venue_endpoint(mut self, endpoint: impl Into<String>) -> Self {
+        self.venue_endpoint = endpoint.into();
+        self
+    }
+
+    /// Overrides the publication export endpoint used by [`Self::fetch_bibtex`]
+    /// and [`Self::fetch_publication_xml`], e.g. to point at a mock server in
+    /// tests.
+    pub fn rec_endpoint(mut self, endpoint: impl Into<String>) -> Self {
+        self.rec_endpoint = endpoint.into();
+        self
+    }
+
+    /// Sets how many times a rate-limited (429) or unavailable (503) request
+    /// is retried before giving up.
+    pub fn max_retries(mut self, max_retries: u32) -> Self {
+        self.max_retries = max_retries;
+        self
+    }
+
+    /// Sets the backoff used between retries when DBLP doesn't send a
+    /// `Retry-After` header. Doubled on each subsequent retry.
+    pub fn base_delay(mut self, base_delay: Duration) -> Self {
+        self.base_delay = base_delay;
+        self
+    }
+
+    /// Sends `request`, retrying on a rate-limited (429) or unavailable
+    /// (503) response up to `self.max_retries` times, honoring the
+    /// `Retry-After` header when present. DBLP's rate limiting isn't scoped
+    /// to the search API, so this is shared by every request the client
+    /// makes.
+    async fn send_with_retry(
+        &self,
+        request: reqwest::RequestBuilder,
+    ) -> anyhow::Result<reqwest::Response> {
+        let mut attempt = 0;
+        loop {
+            let next_request = request
+                .try_clone()
+                .ok_or_else(|| anyhow::anyhow!("request cannot be retried"))?;
+            let response = next_request.send().await?;
+
+            let status = response.status();
+            let is_rate_limited = status == reqwest::StatusCode::TOO_MANY_REQUESTS
+                || status == reqwest::StatusCode::SERVICE_UNAVAILABLE;
+            if is_rate_limited && attempt < self.max_retries {
+                let delay = retry_after(&response).unwrap_or_else(|| {
+                    2u32.checked_pow(attempt)
+                        .map(|multiplier| self.base_delay * multiplier)
+                        .unwrap_or(Duration::MAX)
+                });
+                tokio::time::sleep(delay).await;
+                attempt += 1;
+                continue;
+            }
+
+            return Ok(response.error_for_status()?);
+        }
+    }
+
+    async fn make_request(
+        &self,
+        api_endpoint: &str,
+        query_string: &str,
+        first: usize,
+        count: usize,
+    ) -> anyhow::Result<Value> {
+        let request = self.client.get(api_endpoint).query(&[
+            ("q", query_string.to_owned()),
+            ("format", "json".to_owned()),
+            ("f", first.to_string()),
+            ("h", count.to_string()),
+        ]);
+        let response = self.send_with_retry(request).await?;
+        let result = response.json::<Value>().await?;
+        // println!("{:?}", result);
+        Ok(result["result"]["hits"].to_owned())
+    }
+}
+
+/// Parses the `Retry-After` header's delay-seconds form. DBLP's rate limiter
+/// only ever sends this form, so an HTTP-date `Retry-After` is treated as
+/// absent and falls back to the client's exponential backoff.
+fn retry_after(response: &reqwest::Response) -> Option<Duration> {
+    response
+        .headers()
+        .get(reqwest::header::RETRY_AFTER)?
+        .to_str()
+        .ok()?
+        .parse::<u64>()
+        .ok()
+        .map(Duration::from_secs)
+}
 
-async fn make_request(api_endpoint: &str, query_string: &str) -> anyhow::Result<Value> {
-    let client = reqwest::Client::new();
-    let response = client
-        .get(api_endpoint)
-        .query(&[("q", query_string), ("format", "json")])
-        .send()
-        .await?;
-    let result = response.json::<Value>().await?;
-    // println!("{:?}", result);
-    Ok(result["result"]["hits"].to_owned())
+/// Returns the shared [`DblpClient`] used by the free-standing `search_*`
+/// functions, initializing it on first use.
+fn shared_client() -> &'static DblpClient {
+    static CLIENT: OnceLock<DblpClient> = OnceLock::new();
+    CLIENT.get_or_init(DblpClient::new)
 }
 
-fn process_hits<T: DeserializeOwned>(hits: Value) -> anyhow::Result<Vec<T>> {
+/// A page of search results, as returned by DBLP's `hits` object: the total
+/// number of matches for the query, how many were sent in this page, the
+/// offset of the first one, and the page itself.
+#[derive(Debug)]
+pub struct SearchResults<T> {
+    pub total: usize,
+    pub sent: usize,
+    pub first: usize,
+    pub hits: Vec<T>,
+}
+
+fn parse_hits_count(hits: &Value, field: &str) -> anyhow::Result<usize> {
+    hits[field]
+        .as_str()
+        .ok_or_else(|| anyhow::anyhow!("missing \"{field}\" in DBLP response"))?
+        .parse()
+        .map_err(|e| anyhow::anyhow!("invalid \"{field}\" in DBLP response: {e}"))
+}
+
+fn process_hits<T: DeserializeOwned>(hits: &Value) -> anyhow::Result<Vec<T>> {
     if hits["@total"] == "0" {
-        Ok(vec![])
-    } else if let Value::Array(values_json) = &hits["hit"] {
-        let values = values_json
-            .iter()
-            .map(|v| {
-                // println!("{:?}", v);
-                serde_json::from_value(v["info"].to_owned())
-            })
-            .collect::<Result<Vec<T>, _>>()?;
-        Ok(values)
-    } else {
-        // TODO: Handle this error gracefully
-        panic!()
+        return Ok(vec![]);
     }
+    // `hit` is the same single-vs-array shape as the author/venue/notes
+    // fields: a lone match comes back as a single object rather than a
+    // one-element array.
+    let hits: Vec<Value> = one_or_many_from_value(&hits["hit"]).map_err(anyhow::Error::msg)?;
+    hits.iter()
+        .map(|v| serde_json::from_value(v["info"].to_owned()).map_err(anyhow::Error::from))
+        .collect()
 }
 
-fn deserialise_author_in_publication<'de, D>(deserializer: D) -> Result<Vec<String>, D::Error>
+fn process_search_results<T: DeserializeOwned>(hits: Value) -> anyhow::Result<SearchResults<T>> {
+    let total = parse_hits_count(&hits, "@total")?;
+    let sent = parse_hits_count(&hits, "@sent")?;
+    let first = parse_hits_count(&hits, "@first")?;
+    let hits = process_hits(&hits)?;
+    Ok(SearchResults {
+        total,
+        sent,
+        first,
+        hits,
+    })
+}
+
+/// A JSON shape that DBLP emits either as a single element or, once there is
+/// more than one, as an array of elements. Types that appear in that position
+/// implement this so [`deserialize_one_or_many`] can turn either shape into a
+/// `Vec<Self>` without a bespoke [`serde::de::Visitor`] per field.
+trait FromDblpNode: Sized {
+    fn from_value(value: &Value) -> Result<Self, String>;
+}
+
+impl FromDblpNode for String {
+    fn from_value(value: &Value) -> Result<Self, String> {
+        value
+            .as_str()
+            .map(|s| s.to_owned())
+            .ok_or_else(|| format!("expected a string, found {value}"))
+    }
+}
+
+impl FromDblpNode for Value {
+    fn from_value(value: &Value) -> Result<Self, String> {
+        Ok(value.clone())
+    }
+}
+
+impl FromDblpNode for (String, String) {
+    fn from_value(value: &Value) -> Result<Self, String> {
+        let kind = value["@type"]
+            .as_str()
+            .ok_or_else(|| format!("expected a \"@type\" field, found {value}"))?;
+        let text = value["text"]
+            .as_str()
+            .ok_or_else(|| format!("expected a \"text\" field, found {value}"))?;
+        Ok((kind.to_owned(), text.to_owned()))
+    }
+}
+
+/// An author entry, which is an object with (at least) a `text` field rather
+/// than a bare string.
+struct AuthorName(String);
+
+impl FromDblpNode for AuthorName {
+    fn from_value(value: &Value) -> Result<Self, String> {
+        value["text"]
+            .as_str()
+            .map(|s| AuthorName(s.to_owned()))
+            .ok_or_else(|| format!("expected a \"text\" field, found {value}"))
+    }
+}
+
+fn one_or_many_from_value<T: FromDblpNode>(value: &Value) -> Result<Vec<T>, String> {
+    match value {
+        Value::Array(items) => items.iter().map(T::from_value).collect(),
+        // DBLP sometimes wraps a single-or-many field in an extra single-key
+        // object, e.g. `{"author": ...}` inside the author list of a
+        // publication; unwrap it and recurse into the inner value.
+        Value::Object(map) if map.len() == 1 => {
+            one_or_many_from_value(map.values().next().unwrap())
+        }
+        other => T::from_value(other).map(|v| vec![v]),
+    }
+}
+
+/// Deserialize a field that DBLP emits as a single element when there's only
+/// one, or as an array once there's more than one (and occasionally wrapped
+/// in a single-key object). Mirrors how JSON-LD deserializers normalize a
+/// "value may be one item or a list" field into a `Vec`.
+fn deserialize_one_or_many<'de, D, T>(deserializer: D) -> Result<Vec<T>, D::Error>
 where
     D: serde::de::Deserializer<'de>,
+    T: FromDblpNode,
 {
-    struct JsonVisitor;
+    struct OneOrManyVisitor<T>(PhantomData<T>);
 
-    impl<'de> serde::de::Visitor<'de> for JsonVisitor {
-        type Value = Vec<String>;
+    impl<'de, T: FromDblpNode> serde::de::Visitor<'de> for OneOrManyVisitor<T> {
+        type Value = Vec<T>;
 
         fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
-            formatter.write_str("authors")
+            formatter.write_str("a single value, an array of values, or a wrapper object")
+        }
+
+        fn visit_str<E>(self, s: &str) -> Result<Self::Value, E>
+        where
+            E: serde::de::Error,
+        {
+            T::from_value(&Value::String(s.to_owned()))
+                .map(|v| vec![v])
+                .map_err(E::custom)
+        }
+
+        fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+        where
+            A: SeqAccess<'de>,
+        {
+            let mut result = Vec::new();
+            while let Some(value) = seq.next_element::<Value>()? {
+                result.push(T::from_value(&value).map_err(serde::de::Error::custom)?);
+            }
+            Ok(result)
         }
 
         fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
@@ -62,63 +343,91 @@ where
             A: MapAccess<'de>,
         {
             let _ = map.next_key::<String>()?;
-            let entry = map.next_value::<serde_json::Value>()?;
-            if let Value::Array(authors) = entry {
-                // When there are multiple authors, the results are in an array
-                let author_strs = authors
-                    .iter()
-                    .map(|v| v["text"].as_str().unwrap().to_owned())
-                    .collect();
-                Ok(author_strs)
-            } else if let Value::Object(author) = entry {
-                // When there is a single author, the result is as an object
-                Ok(vec![author["text"].as_str().unwrap().to_owned()])
-            } else {
-                panic!()
-            }
+            let entry = map.next_value::<Value>()?;
+            one_or_many_from_value(&entry).map_err(serde::de::Error::custom)
         }
     }
-    deserializer.deserialize_any(JsonVisitor)
+
+    deserializer.deserialize_any(OneOrManyVisitor(PhantomData))
+}
+
+fn deserialise_author_in_publication<'de, D>(deserializer: D) -> Result<Vec<String>, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    let authors: Vec<AuthorName> = deserialize_one_or_many(deserializer)?;
+    Ok(authors.into_iter().map(|author| author.0).collect())
 }
 
 fn deserialise_venue_in_publication<'de, D>(deserializer: D) -> Result<Vec<String>, D::Error>
 where
     D: serde::de::Deserializer<'de>,
 {
-    struct JsonVisitor;
+    deserialize_one_or_many(deserializer)
+}
 
-    impl<'de> serde::de::Visitor<'de> for JsonVisitor {
-        type Value = Vec<String>;
+/// A builder for DBLP's search query syntax: terms are ANDed together, with
+/// prefix wildcards and quoted phrases supported alongside plain terms.
+#[derive(Debug, Default, Clone)]
+pub struct Query {
+    terms: Vec<String>,
+}
 
-        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
-            formatter.write_str("venues")
-        }
+impl Query {
+    pub fn new() -> Self {
+        Query::default()
+    }
 
-        fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
-        where
-            A: SeqAccess<'de>,
-        {
-            let mut venues = Vec::new();
-            while let Some(Value::String(venue)) = seq.next_element()? {
-                venues.push(venue);
-            }
-            Ok(venues)
-        }
+    /// Adds a plain term, ANDed with any terms already in the query.
+    pub fn term(mut self, term: &str) -> Self {
+        self.terms.push(term.to_owned());
+        self
+    }
 
-        fn visit_str<E>(self, s: &str) -> Result<Self::Value, E> {
-            Ok(vec![s.to_owned()])
-        }
+    /// Adds a prefix term, matching anything starting with `prefix`.
+    pub fn prefix(mut self, prefix: &str) -> Self {
+        self.terms.push(format!("{prefix}*"));
+        self
+    }
+
+    /// Adds a quoted phrase, matched as a contiguous sequence of words.
+    pub fn phrase(mut self, phrase: &str) -> Self {
+        self.terms.push(format!("\"{phrase}\""));
+        self
+    }
+
+    /// Adds every one of `terms` as a plain term, ANDed with any terms
+    /// already in the query.
+    pub fn all_of(mut self, terms: &[&str]) -> Self {
+        self.terms.extend(terms.iter().map(|term| (*term).to_owned()));
+        self
+    }
+
+    /// Builds the final `q` query string.
+    pub fn build(self) -> String {
+        self.terms.join(" ")
+    }
+}
+
+impl From<&str> for Query {
+    fn from(term: &str) -> Self {
+        Query::new().term(term)
+    }
+}
+
+impl From<String> for Query {
+    fn from(term: String) -> Self {
+        Query::new().term(&term)
     }
-    deserializer.deserialize_any(JsonVisitor)
 }
 
 #[derive(Deserialize, Debug)]
 //#[serde(deny_unknown_fields)]
 pub struct Publication {
-    #[serde(deserialize_with = "deserialise_author_in_publication")]
+    #[serde(deserialize_with = "deserialise_author_in_publication", default)]
     pub authors: Vec<String>,
     pub title: String,
-    #[serde(deserialize_with = "deserialise_venue_in_publication")]
+    #[serde(deserialize_with = "deserialise_venue_in_publication", default)]
     pub venue: Vec<String>,
     pub year: String,
     pub r#type: String,
@@ -133,6 +442,58 @@ pub struct Publication {
     pub number: Option<String>,
 }
 
+impl DblpClient {
+    pub async fn search_publication(
+        &self,
+        query: impl Into<Query>,
+    ) -> anyhow::Result<Vec<Publication>> {
+        let results = self
+            .search_publication_page(query, DEFAULT_FIRST, DEFAULT_COUNT)
+            .await?;
+        Ok(results.hits)
+    }
+
+    /// Search for a publication, fetching a single page of up to `count`
+    /// results starting at offset `first`.
+    pub async fn search_publication_page(
+        &self,
+        query: impl Into<Query>,
+        first: usize,
+        count: usize,
+    ) -> anyhow::Result<SearchResults<Publication>> {
+        let query_string = query.into().build();
+        let hits = self
+            .make_request(&self.publication_endpoint, &query_string, first, count)
+            .await?;
+        process_search_results(hits)
+    }
+
+    /// Search for a publication, fetching every page of results for the
+    /// query.
+    pub async fn search_publication_all(
+        &self,
+        query: impl Into<Query>,
+    ) -> anyhow::Result<Vec<Publication>> {
+        let query = query.into();
+        let mut pubs = Vec::new();
+        let mut first = DEFAULT_FIRST;
+        loop {
+            let mut page = self
+                .search_publication_page(query.clone(), first, MAX_COUNT)
+                .await?;
+            if page.sent == 0 {
+                break;
+            }
+            first += page.sent;
+            pubs.append(&mut page.hits);
+            if first >= page.total {
+                break;
+            }
+        }
+        Ok(pubs)
+    }
+}
+
 /// Search for a publication, returns a JSON value
 /// ```
 /// # async fn publication() -> anyhow::Result<()> {
@@ -140,92 +501,88 @@ pub struct Publication {
 /// let result = search_publication("The Part-Time Parliament").await;
 /// # Ok(()) }
 /// ```
-pub async fn search_publication(query_string: &str) -> anyhow::Result<Vec<Publication>> {
-    let hits = make_request(PUBLICATION_API_ENDPOINT, query_string).await?;
-    let pubs = process_hits(hits)?;
-    Ok(pubs)
+pub async fn search_publication(query: impl Into<Query>) -> anyhow::Result<Vec<Publication>> {
+    shared_client().search_publication(query).await
 }
 
-fn deserialise_notes_in_author<'de, D>(deserializer: D) -> Result<Vec<(String, String)>, D::Error>
-where
-    D: serde::de::Deserializer<'de>,
-{
-    struct JsonVisitor;
+/// Search for a publication, fetching a single page of up to `count` results
+/// starting at offset `first`.
+pub async fn search_publication_page(
+    query: impl Into<Query>,
+    first: usize,
+    count: usize,
+) -> anyhow::Result<SearchResults<Publication>> {
+    shared_client()
+        .search_publication_page(query, first, count)
+        .await
+}
 
-    impl<'de> serde::de::Visitor<'de> for JsonVisitor {
-        type Value = Vec<(String, String)>;
+/// Search for a publication, fetching every page of results for the query.
+/// ```
+/// # async fn publication() -> anyhow::Result<()> {
+/// use dblp_rs::search_publication_all;
+/// let result = search_publication_all("proceedings").await;
+/// # Ok(()) }
+/// ```
+pub async fn search_publication_all(query: impl Into<Query>) -> anyhow::Result<Vec<Publication>> {
+    shared_client().search_publication_all(query).await
+}
 
-        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
-            formatter.write_str("notes")
-        }
+impl DblpClient {
+    async fn fetch_export(&self, key: &str, extension: &str) -> anyhow::Result<String> {
+        let url = format!("{}/{key}.{extension}", self.rec_endpoint);
+        let response = self.send_with_retry(self.client.get(url)).await?;
+        Ok(response.text().await?)
+    }
 
-        fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
-        where
-            A: MapAccess<'de>,
-        {
-            let _ = map.next_key::<String>()?;
-            let entry = map.next_value::<serde_json::Value>()?;
-            if let Value::Array(notes) = entry {
-                let notes = notes
-                    .iter()
-                    .map(|v| {
-                        (
-                            v["@type"].as_str().unwrap().to_owned(),
-                            v["text"].as_str().unwrap().to_owned(),
-                        )
-                    })
-                    .collect();
-                Ok(notes)
-            } else if let Value::Object(note) = entry {
-                Ok(vec![(
-                    note["@type"].as_str().unwrap().to_owned(),
-                    note["text"].as_str().unwrap().to_owned(),
-                )])
-            } else {
-                panic!()
-            }
-        }
+    /// Fetches the BibTeX entry for the publication with the given DBLP key,
+    /// e.g. `"conf/podc/Lamport98"`.
+    pub async fn fetch_bibtex(&self, key: &str) -> anyhow::Result<String> {
+        self.fetch_export(key, "bib").await
+    }
+
+    /// Fetches the raw DBLP XML record for the publication with the given
+    /// DBLP key, e.g. `"conf/podc/Lamport98"`.
+    pub async fn fetch_publication_xml(&self, key: &str) -> anyhow::Result<String> {
+        self.fetch_export(key, "xml").await
     }
-    deserializer.deserialize_any(JsonVisitor)
 }
 
-fn deserialise_aliases_in_author<'de, D>(deserializer: D) -> Result<Vec<String>, D::Error>
+/// Fetches the BibTeX entry for the publication with the given DBLP key.
+/// ```
+/// # async fn bibtex() -> anyhow::Result<()> {
+/// use dblp_rs::fetch_bibtex;
+/// let result = fetch_bibtex("journals/tocs/Lamport98").await;
+/// # Ok(()) }
+/// ```
+pub async fn fetch_bibtex(key: &str) -> anyhow::Result<String> {
+    shared_client().fetch_bibtex(key).await
+}
+
+/// Fetches the raw DBLP XML record for the publication with the given DBLP
+/// key.
+/// ```
+/// # async fn publication_xml() -> anyhow::Result<()> {
+/// use dblp_rs::fetch_publication_xml;
+/// let result = fetch_publication_xml("journals/tocs/Lamport98").await;
+/// # Ok(()) }
+/// ```
+pub async fn fetch_publication_xml(key: &str) -> anyhow::Result<String> {
+    shared_client().fetch_publication_xml(key).await
+}
+
+fn deserialise_notes_in_author<'de, D>(deserializer: D) -> Result<Vec<(String, String)>, D::Error>
 where
     D: serde::de::Deserializer<'de>,
 {
-    struct JsonVisitor;
-
-    impl<'de> serde::de::Visitor<'de> for JsonVisitor {
-        type Value = Vec<String>;
-
-        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
-            formatter.write_str("aliases")
-        }
-
-        fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
-        where
-            A: MapAccess<'de>,
-        {
-            let _ = map.next_key::<String>()?;
-            let entry = map.next_value::<serde_json::Value>()?;
-            if let Value::Array(aliases) = entry {
-                let aliases = aliases
-                    .iter()
-                    .map(|v| v.as_str().unwrap().to_owned())
-                    .collect();
-                Ok(aliases)
-            } else if let Value::String(alias) = entry {
-                Ok(vec![alias])
-            } else {
-                panic!()
-            }
-        }
+    deserialize_one_or_many(deserializer)
+}
 
-        fn visit_str<E>(self, s: &str) -> Result<Self::Value, E> {
-            Ok(vec![s.to_owned()])
-        }
-    }
-    deserializer.deserialize_any(JsonVisitor)
+fn deserialise_aliases_in_author<'de, D>(deserializer: D) -> Result<Vec<String>, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    deserialize_one_or_many(deserializer)
 }
 
 #[derive(Deserialize)]
@@ -239,6 +596,54 @@ pub struct Author {
     pub aliases: Vec<String>,
 }
 
+impl DblpClient {
+    pub async fn search_author(&self, query: impl Into<Query>) -> anyhow::Result<Vec<Author>> {
+        let results = self
+            .search_author_page(query, DEFAULT_FIRST, DEFAULT_COUNT)
+            .await?;
+        Ok(results.hits)
+    }
+
+    /// Search for an author, fetching a single page of up to `count` results
+    /// starting at offset `first`.
+    pub async fn search_author_page(
+        &self,
+        query: impl Into<Query>,
+        first: usize,
+        count: usize,
+    ) -> anyhow::Result<SearchResults<Author>> {
+        let query_string = query.into().build();
+        let hits = self
+            .make_request(&self.author_endpoint, &query_string, first, count)
+            .await?;
+        process_search_results(hits)
+    }
+
+    /// Search for an author, fetching every page of results for the query.
+    pub async fn search_author_all(
+        &self,
+        query: impl Into<Query>,
+    ) -> anyhow::Result<Vec<Author>> {
+        let query = query.into();
+        let mut authors = Vec::new();
+        let mut first = DEFAULT_FIRST;
+        loop {
+            let mut page = self
+                .search_author_page(query.clone(), first, MAX_COUNT)
+                .await?;
+            if page.sent == 0 {
+                break;
+            }
+            first += page.sent;
+            authors.append(&mut page.hits);
+            if first >= page.total {
+                break;
+            }
+        }
+        Ok(authors)
+    }
+}
+
 /// Search for an author, returns a JSON value
 /// ```
 /// # async fn author() -> anyhow::Result<()> {
@@ -246,10 +651,23 @@ pub struct Author {
 /// let result = search_author("Leslie Lamport").await;
 /// # Ok(()) }
 /// ```
-pub async fn search_author(query_string: &str) -> anyhow::Result<Vec<Author>> {
-    let hits = make_request(AUTHOR_API_ENDPOINT, query_string).await?;
-    let authors = process_hits(hits)?;
-    Ok(authors)
+pub async fn search_author(query: impl Into<Query>) -> anyhow::Result<Vec<Author>> {
+    shared_client().search_author(query).await
+}
+
+/// Search for an author, fetching a single page of up to `count` results
+/// starting at offset `first`.
+pub async fn search_author_page(
+    query: impl Into<Query>,
+    first: usize,
+    count: usize,
+) -> anyhow::Result<SearchResults<Author>> {
+    shared_client().search_author_page(query, first, count).await
+}
+
+/// Search for an author, fetching every page of results for the query.
+pub async fn search_author_all(query: impl Into<Query>) -> anyhow::Result<Vec<Author>> {
+    shared_client().search_author_all(query).await
 }
 
 #[derive(Deserialize)]
@@ -261,6 +679,51 @@ pub struct Venue {
     pub url: String,
 }
 
+impl DblpClient {
+    pub async fn search_venue(&self, query: impl Into<Query>) -> anyhow::Result<Vec<Venue>> {
+        let results = self
+            .search_venue_page(query, DEFAULT_FIRST, DEFAULT_COUNT)
+            .await?;
+        Ok(results.hits)
+    }
+
+    /// Search for a venue, fetching a single page of up to `count` results
+    /// starting at offset `first`.
+    pub async fn search_venue_page(
+        &self,
+        query: impl Into<Query>,
+        first: usize,
+        count: usize,
+    ) -> anyhow::Result<SearchResults<Venue>> {
+        let query_string = query.into().build();
+        let hits = self
+            .make_request(&self.venue_endpoint, &query_string, first, count)
+            .await?;
+        process_search_results(hits)
+    }
+
+    /// Search for a venue, fetching every page of results for the query.
+    pub async fn search_venue_all(&self, query: impl Into<Query>) -> anyhow::Result<Vec<Venue>> {
+        let query = query.into();
+        let mut venues = Vec::new();
+        let mut first = DEFAULT_FIRST;
+        loop {
+            let mut page = self
+                .search_venue_page(query.clone(), first, MAX_COUNT)
+                .await?;
+            if page.sent == 0 {
+                break;
+            }
+            first += page.sent;
+            venues.append(&mut page.hits);
+            if first >= page.total {
+                break;
+            }
+        }
+        Ok(venues)
+    }
+}
+
 /// Search for a venue, returns a JSON value
 /// ```
 /// # async fn venue() -> anyhow::Result<()> {
@@ -268,15 +731,27 @@ pub struct Venue {
 /// let result = search_venue("TOCS").await;
 /// # Ok(()) }
 /// ```
-pub async fn search_venue(query_string: &str) -> anyhow::Result<Vec<Venue>> {
-    let hits = make_request(VENUE_API_ENDPOINT, query_string).await?;
-    let venues = process_hits(hits)?;
-    Ok(venues)
+pub async fn search_venue(query: impl Into<Query>) -> anyhow::Result<Vec<Venue>> {
+    shared_client().search_venue(query).await
+}
+
+/// Search for a venue, fetching a single page of up to `count` results
+/// starting at offset `first`.
+pub async fn search_venue_page(
+    query: impl Into<Query>,
+    first: usize,
+    count: usize,
+) -> anyhow::Result<SearchResults<Venue>> {
+    shared_client().search_venue_page(query, first, count).await
+}
+
+/// Search for a venue, fetching every page of results for the query.
+pub async fn search_venue_all(query: impl Into<Query>) -> anyhow::Result<Vec<Venue>> {
+    shared_client().search_venue_all(query).await
 }
 
 #[cfg(test)]
 mod tests {
-    extern crate tokio;
     use super::*;
 
     #[tokio::test]
@@ -314,4 +789,147 @@ mod tests {
         let result = search_venue("Transactions").await;
         result.unwrap();
     }
+
+    #[tokio::test]
+    async fn integration_test_publication_page() {
+        let result = search_publication_page("proceedings", 0, 10).await.unwrap();
+        assert_eq!(result.hits.len(), result.sent);
+        assert!(result.total >= result.sent);
+    }
+
+    #[tokio::test]
+    async fn integration_test_publication_all() {
+        let result = search_publication_all("The Part-Time Parliament")
+            .await
+            .unwrap();
+        assert!(!result.is_empty());
+    }
+
+    #[tokio::test]
+    async fn integration_test_client_publication() {
+        let client = DblpClient::new();
+        let result = client
+            .search_publication("The Part-Time Parliament")
+            .await;
+        result.unwrap();
+    }
+
+    #[tokio::test]
+    async fn integration_test_client_bad_publication_endpoint() {
+        let client = DblpClient::new().publication_endpoint("https://dblp.org/does-not-exist");
+        let result = client.search_publication("The Part-Time Parliament").await;
+        assert!(result.is_err());
+    }
+
+    fn stub_publication_page(first: usize, sent: usize, total: usize) -> serde_json::Value {
+        serde_json::json!({
+            "result": {
+                "hits": {
+                    "@total": total.to_string(),
+                    "@sent": sent.to_string(),
+                    "@first": first.to_string(),
+                    "hit": {
+                        "@score": "1",
+                        "@id": first.to_string(),
+                        "info": {
+                            "title": "Example Paper",
+                            "year": "2020",
+                            "type": "Conference and Workshop Papers",
+                            "key": format!("conf/test/Example{first}"),
+                            "ee": "https://doi.org/10.1/example",
+                            "url": "https://dblp.org/rec/conf/test/Example",
+                        }
+                    }
+                }
+            }
+        })
+    }
+
+    #[tokio::test]
+    async fn stub_test_publication_pagination() {
+        let server = wiremock::MockServer::start().await;
+        wiremock::Mock::given(wiremock::matchers::query_param("f", "0"))
+            .respond_with(
+                wiremock::ResponseTemplate::new(200).set_body_json(stub_publication_page(0, 1, 2)),
+            )
+            .mount(&server)
+            .await;
+        wiremock::Mock::given(wiremock::matchers::query_param("f", "1"))
+            .respond_with(
+                wiremock::ResponseTemplate::new(200).set_body_json(stub_publication_page(1, 1, 2)),
+            )
+            .mount(&server)
+            .await;
+
+        let client = DblpClient::new().publication_endpoint(server.uri());
+        let result = client.search_publication_all("test").await.unwrap();
+        assert_eq!(result.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn stub_test_retries_on_rate_limit() {
+        let server = wiremock::MockServer::start().await;
+        wiremock::Mock::given(wiremock::matchers::method("GET"))
+            .respond_with(wiremock::ResponseTemplate::new(429))
+            .up_to_n_times(1)
+            .mount(&server)
+            .await;
+        wiremock::Mock::given(wiremock::matchers::method("GET"))
+            .respond_with(
+                wiremock::ResponseTemplate::new(200).set_body_json(stub_publication_page(0, 1, 1)),
+            )
+            .mount(&server)
+            .await;
+
+        let client = DblpClient::new()
+            .publication_endpoint(server.uri())
+            .base_delay(Duration::from_millis(1));
+        let result = client.search_publication("test").await.unwrap();
+        assert_eq!(result.len(), 1);
+    }
+
+    #[test]
+    fn query_builder_joins_terms_with_spaces() {
+        let query = Query::new().term("lamport").term("paxos");
+        assert_eq!(query.build(), "lamport paxos");
+    }
+
+    #[test]
+    fn query_builder_supports_prefix_phrase_and_all_of() {
+        let query = Query::new()
+            .prefix("lamp")
+            .phrase("part time parliament")
+            .all_of(&["paxos", "consensus"]);
+        assert_eq!(
+            query.build(),
+            "lamp* \"part time parliament\" paxos consensus"
+        );
+    }
+
+    #[test]
+    fn str_into_query_is_a_single_term() {
+        let query: Query = "lamport".into();
+        assert_eq!(query.build(), "lamport");
+    }
+
+    #[tokio::test]
+    async fn integration_test_publication_with_query_builder() {
+        let query = Query::new().phrase("The Part-Time Parliament");
+        let result = search_publication(query).await;
+        result.unwrap();
+    }
+
+    #[tokio::test]
+    async fn integration_test_fetch_bibtex() {
+        let result = fetch_bibtex("journals/tocs/Lamport98").await.unwrap();
+        assert!(result.contains("@article"));
+    }
+
+    #[tokio::test]
+    async fn integration_test_fetch_publication_xml() {
+        let result = fetch_publication_xml("journals/tocs/Lamport98")
+            .await
+            .unwrap();
+        assert!(result.contains("<article"));
+    }
 }